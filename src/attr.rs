@@ -0,0 +1,114 @@
+//! Predicate parsing and evaluation backing the `#[compile_time::since]`-family attribute
+//! macros in [`crate`].
+
+use proc_macro::TokenStream;
+use syn::parse::{Parse, Parser};
+use syn::punctuated::Punctuated;
+use syn::{parenthesized, LitStr, Token};
+
+/// A compiler-conditional predicate, as parsed from an attribute macro's arguments.
+pub(crate) enum Predicate {
+  Since(semver::Version),
+  Before(semver::Version),
+  Stable,
+  Nightly,
+  Beta,
+  Any(Vec<Predicate>),
+  All(Vec<Predicate>),
+  Not(Box<Predicate>),
+}
+
+impl Parse for Predicate {
+  fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+    let ident: syn::Ident = input.parse()?;
+
+    match ident.to_string().as_str() {
+      "since" | "before" => {
+        let content;
+        parenthesized!(content in input);
+        let version = parse_version(&content)?;
+
+        Ok(if ident == "since" { Predicate::Since(version) } else { Predicate::Before(version) })
+      }
+      "stable" => Ok(Predicate::Stable),
+      "nightly" => Ok(Predicate::Nightly),
+      "beta" => Ok(Predicate::Beta),
+      "any" | "all" => {
+        let content;
+        parenthesized!(content in input);
+        let predicates = Punctuated::<Predicate, Token![,]>::parse_terminated(&content)?.into_iter().collect();
+
+        Ok(if ident == "any" { Predicate::Any(predicates) } else { Predicate::All(predicates) })
+      }
+      "not" => {
+        let content;
+        parenthesized!(content in input);
+
+        Ok(Predicate::Not(Box::new(content.parse()?)))
+      }
+      other => Err(syn::Error::new(
+        ident.span(),
+        format!("unknown predicate `{other}`, expected one of: since, before, stable, nightly, beta, any, all, not"),
+      )),
+    }
+  }
+}
+
+fn parse_version(input: syn::parse::ParseStream) -> syn::Result<semver::Version> {
+  let lit: LitStr = input.parse()?;
+
+  semver::Version::parse(&lit.value()).map_err(|err| syn::Error::new(lit.span(), format!("invalid version: {err}")))
+}
+
+fn rustc_version() -> &'static semver::Version {
+  match &*crate::RUSTC_VERSION {
+    Ok(rustc_version) => rustc_version,
+    Err(err) => panic!("Failed to get version: {}", err),
+  }
+}
+
+fn channel() -> rustc_version::Channel {
+  match &*crate::RUSTC_VERSION_META {
+    Ok(version_meta) => version_meta.channel,
+    Err(err) => panic!("Failed to get version meta: {}", err),
+  }
+}
+
+fn eval(predicate: &Predicate) -> bool {
+  match predicate {
+    Predicate::Since(version) => !matches!(rustc_version().cmp_precedence(version), std::cmp::Ordering::Less),
+    Predicate::Before(version) => matches!(rustc_version().cmp_precedence(version), std::cmp::Ordering::Less),
+    Predicate::Stable => channel() == rustc_version::Channel::Stable,
+    Predicate::Nightly => channel() == rustc_version::Channel::Nightly,
+    Predicate::Beta => channel() == rustc_version::Channel::Beta,
+    Predicate::Any(predicates) => predicates.iter().any(eval),
+    Predicate::All(predicates) => predicates.iter().all(eval),
+    Predicate::Not(predicate) => !eval(predicate),
+  }
+}
+
+/// Parses a bare version string literal, such as the argument to `#[compile_time::since(...)]`.
+pub(crate) fn parse_bare_version(args: TokenStream, since: bool) -> syn::Result<Predicate> {
+  let lit: LitStr = syn::parse(args)?;
+  let version = semver::Version::parse(&lit.value()).map_err(|err| syn::Error::new(lit.span(), format!("invalid version: {err}")))?;
+
+  Ok(if since { Predicate::Since(version) } else { Predicate::Before(version) })
+}
+
+/// Parses a comma-separated list of predicates, such as the arguments to
+/// `#[compile_time::attr(...)]`, combining them with AND.
+pub(crate) fn parse_conjunction(args: TokenStream) -> syn::Result<Predicate> {
+  let predicates = Punctuated::<Predicate, Token![,]>::parse_terminated.parse(args)?;
+
+  Ok(Predicate::All(predicates.into_iter().collect()))
+}
+
+/// Keeps `item` unchanged if `predicate` evaluates to `true`, drops it otherwise, or emits a
+/// `compile_error!` if `predicate` failed to parse.
+pub(crate) fn apply(item: TokenStream, predicate: syn::Result<Predicate>) -> TokenStream {
+  match predicate {
+    Ok(predicate) if eval(&predicate) => item,
+    Ok(_) => TokenStream::new(),
+    Err(err) => TokenStream::from(err.to_compile_error()),
+  }
+}
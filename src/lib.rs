@@ -4,10 +4,35 @@
 //! [`chrono::NaiveDate`](chrono::NaiveDate), [`chrono::NaiveTime`](chrono::NaiveTime),
 //! [`chrono::DateTime<Utc>`](chrono::DateTime), string, or UNIX timestamp.
 //!
+//! The compile time honors `SOURCE_DATE_EPOCH` when set, so builds stay reproducible.
+//!
 //! You can get the Rust compiler version either as
 //! [`semver::Version`](semver::Version) or string,
 //! and the individual version parts as integer literals or strings, respectively.
 //!
+//! The Rust compiler release channel (`stable`, `beta`, `nightly`, or `dev`) is available
+//! either as [`rustc_version::Channel`](rustc_version::Channel) or string.
+//!
+//! For reproducible-build provenance, the compiler's commit hash, commit date, host
+//! target triple, and LLVM version are also exposed.
+//!
+//! Version gates such as [`rustc_at_least!`](rustc_at_least), [`rustc_before!`](rustc_before),
+//! and [`rustc_exactly!`](rustc_exactly) expand to a const-evaluable `bool` so crates can
+//! branch on the detected compiler version without a build script.
+//!
+//! Date gates such as [`rustc_since_date!`](rustc_since_date), [`rustc_before_date!`](rustc_before_date),
+//! and [`rustc_on_date!`](rustc_on_date) compare against the compiler's commit date instead.
+//!
+//! Attribute macros such as [`since`](macro@since), [`before`](macro@before),
+//! [`stable`](macro@stable), [`nightly`](macro@nightly), [`beta`](macro@beta), and
+//! [`attr`](macro@attr) keep or drop the annotated item based on the detected compiler,
+//! in the spirit of the `rustversion` crate.
+//!
+//! The compile date and time can also be formatted with an arbitrary `strftime` format
+//! string via [`date_fmt!`](date_fmt), [`time_fmt!`](time_fmt), and
+//! [`datetime_fmt!`](datetime_fmt), or rendered in the builder's local timezone via
+//! [`datetime_local!`](datetime_local) and [`datetime_local_str!`](datetime_local_str).
+//!
 //! # Example
 //!
 //! ```
@@ -23,9 +48,24 @@ use chrono::{DateTime, Datelike, Timelike, Utc};
 use once_cell::sync::Lazy;
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
+use syn::LitStr;
+
+mod attr;
 
-static COMPILE_TIME: Lazy<DateTime<Utc>> = Lazy::new(Utc::now);
-static RUSTC_VERSION: Lazy<rustc_version::Result<rustc_version::Version>> = Lazy::new(rustc_version::version);
+static COMPILE_TIME: Lazy<DateTime<Utc>> = Lazy::new(compile_time);
+
+/// Resolves the compile time, honoring `SOURCE_DATE_EPOCH` (the reproducible-builds
+/// convention: a UNIX timestamp in seconds) when it is set and parses as one, falling back
+/// to the wall clock otherwise.
+fn compile_time() -> DateTime<Utc> {
+  std::env::var("SOURCE_DATE_EPOCH")
+    .ok()
+    .and_then(|secs| secs.parse::<i64>().ok())
+    .and_then(|secs| DateTime::from_timestamp(secs, 0))
+    .unwrap_or_else(Utc::now)
+}
+pub(crate) static RUSTC_VERSION: Lazy<rustc_version::Result<rustc_version::Version>> = Lazy::new(rustc_version::version);
+pub(crate) static RUSTC_VERSION_META: Lazy<rustc_version::Result<rustc_version::VersionMeta>> = Lazy::new(rustc_version::version_meta);
 
 /// Compile date as `chrono::NaiveDate`.
 ///
@@ -220,6 +260,142 @@ pub fn datetime_str(_item: TokenStream) -> TokenStream {
   quote! { #datetime_str }.into()
 }
 
+fn parse_format_arg(item: TokenStream) -> Result<String, TokenStream> {
+  let lit = syn::parse::<LitStr>(item).map_err(|err| TokenStream::from(err.to_compile_error()))?;
+  let fmt = lit.value();
+
+  let has_invalid_specifier = chrono::format::StrftimeItems::new(&fmt).any(|item| matches!(item, chrono::format::Item::Error));
+  if has_invalid_specifier {
+    return Err(TokenStream::from(syn::Error::new(lit.span(), format!("invalid strftime format: `{fmt}`")).to_compile_error()));
+  }
+
+  Ok(fmt)
+}
+
+/// Compile date as `&'static str`, formatted using the given `strftime` format string.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(compile_time::date_fmt!("%Y-%m-%d"), compile_time::date_str!());
+/// ```
+#[proc_macro]
+pub fn date_fmt(item: TokenStream) -> TokenStream {
+  let fmt = match parse_format_arg(item) {
+    Ok(fmt) => fmt,
+    Err(err) => return err,
+  };
+
+  let date_str = COMPILE_TIME.date_naive().format(&fmt).to_string();
+
+  quote! { #date_str }.into()
+}
+
+/// Compile time as `&'static str`, formatted using the given `strftime` format string.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(compile_time::time_fmt!("%H:%M:%S"), compile_time::time_str!());
+/// ```
+#[proc_macro]
+pub fn time_fmt(item: TokenStream) -> TokenStream {
+  let fmt = match parse_format_arg(item) {
+    Ok(fmt) => fmt,
+    Err(err) => return err,
+  };
+
+  let time_str = COMPILE_TIME.time().format(&fmt).to_string();
+
+  quote! { #time_str }.into()
+}
+
+/// Compile date and time as `&'static str`, formatted using the given `strftime` format string.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(compile_time::datetime_fmt!("%Y-%m-%dT%H:%M:%SZ"), compile_time::datetime_str!());
+/// ```
+#[proc_macro]
+pub fn datetime_fmt(item: TokenStream) -> TokenStream {
+  let fmt = match parse_format_arg(item) {
+    Ok(fmt) => fmt,
+    Err(err) => return err,
+  };
+
+  let datetime_str = COMPILE_TIME.format(&fmt).to_string();
+
+  quote! { #datetime_str }.into()
+}
+
+/// Compile date and time as `chrono::DateTime<chrono::FixedOffset>`, rendered in the
+/// builder's local timezone (via [`chrono::Local`](chrono::Local)) with the UTC offset baked in.
+///
+/// # Example
+///
+/// ```
+/// let compile_datetime_local = compile_time::datetime_local!();
+/// assert_eq!(compile_datetime_local, compile_time::datetime!());
+/// ```
+#[proc_macro]
+pub fn datetime_local(_item: TokenStream) -> TokenStream {
+  let local = COMPILE_TIME.with_timezone(&chrono::Local);
+  let naive = local.naive_local();
+
+  let year = naive.year();
+  let month = naive.month();
+  let day = naive.day();
+
+  let hour = naive.hour();
+  let minute = naive.minute();
+  let second = naive.second();
+
+  let offset_secs = local.offset().local_minus_utc();
+
+  quote! {
+    {
+      let naive = ::chrono::NaiveDateTime::new(
+        match ::chrono::NaiveDate::from_ymd_opt(#year, #month, #day) {
+          Some(date) => date,
+          _ => ::core::unreachable!(),
+        },
+        match ::chrono::NaiveTime::from_hms_opt(#hour, #minute, #second) {
+          Some(time) => time,
+          _ => ::core::unreachable!(),
+        },
+      );
+
+      match ::chrono::FixedOffset::east_opt(#offset_secs) {
+        Some(offset) => match ::chrono::TimeZone::from_local_datetime(&offset, &naive) {
+          ::chrono::LocalResult::Single(datetime) => datetime,
+          _ => ::core::unreachable!(),
+        },
+        _ => ::core::unreachable!(),
+      }
+    }
+  }
+  .into()
+}
+
+/// Compile date and time as `&'static str` in RFC 3339 format, rendered in the builder's
+/// local timezone (via [`chrono::Local`](chrono::Local)) with the UTC offset included.
+///
+/// # Example
+///
+/// ```
+/// let compile_datetime_local_str = compile_time::datetime_local_str!();
+/// assert!(compile_datetime_local_str.len() >= compile_time::datetime_str!().len());
+/// ```
+#[proc_macro]
+pub fn datetime_local_str(_item: TokenStream) -> TokenStream {
+  let local = COMPILE_TIME.with_timezone(&chrono::Local);
+
+  let datetime_str = local.to_rfc3339();
+
+  quote! { #datetime_str }.into()
+}
+
 /// Compile date and time as UNIX timestamp in seconds.
 ///
 /// # Example
@@ -402,3 +578,450 @@ pub fn rustc_version_build(_item: TokenStream) -> TokenStream {
 
   quote! { #build }.into()
 }
+
+fn channel_tokens(channel: rustc_version::Channel) -> proc_macro2::TokenStream {
+  match channel {
+    rustc_version::Channel::Dev => quote! { ::rustc_version::Channel::Dev },
+    rustc_version::Channel::Nightly => quote! { ::rustc_version::Channel::Nightly },
+    rustc_version::Channel::Beta => quote! { ::rustc_version::Channel::Beta },
+    rustc_version::Channel::Stable => quote! { ::rustc_version::Channel::Stable },
+  }
+}
+
+fn channel_str(channel: rustc_version::Channel) -> &'static str {
+  match channel {
+    rustc_version::Channel::Dev => "dev",
+    rustc_version::Channel::Nightly => "nightly",
+    rustc_version::Channel::Beta => "beta",
+    rustc_version::Channel::Stable => "stable",
+  }
+}
+
+/// Rust compiler release channel as `rustc_version::Channel`.
+///
+/// # Example
+///
+/// ```
+/// let channel: rustc_version::Channel = compile_time::rustc_channel!();
+/// assert_eq!(channel, rustc_version::version_meta().unwrap().channel);
+/// ```
+#[proc_macro]
+pub fn rustc_channel(_item: TokenStream) -> TokenStream {
+  let channel = match &*RUSTC_VERSION_META {
+    Ok(version_meta) => version_meta.channel,
+    Err(err) => panic!("Failed to get version meta: {}", err),
+  };
+
+  channel_tokens(channel).into()
+}
+
+/// Rust compiler release channel as `&'static str` (`"stable"`, `"beta"`, `"nightly"`, or `"dev"`).
+///
+/// # Example
+///
+/// ```
+/// const RUSTC_CHANNEL: &str = compile_time::rustc_channel_str!();
+/// assert_eq!(RUSTC_CHANNEL, compile_time::rustc_channel_str!());
+/// ```
+#[proc_macro]
+pub fn rustc_channel_str(_item: TokenStream) -> TokenStream {
+  let channel = match &*RUSTC_VERSION_META {
+    Ok(version_meta) => version_meta.channel,
+    Err(err) => panic!("Failed to get version meta: {}", err),
+  };
+
+  let channel_str = channel_str(channel);
+  quote! { #channel_str }.into()
+}
+
+fn optional_str_tokens(value: &Option<String>) -> proc_macro2::TokenStream {
+  match value {
+    Some(value) => {
+      let value = value.as_str();
+      quote! { ::core::option::Option::Some(#value) }
+    }
+    None => quote! { ::core::option::Option::None },
+  }
+}
+
+/// Commit hash of the Rust compiler as `Option<&'static str>`.
+///
+/// `None` if the compiler was not built from a git checkout.
+///
+/// # Example
+///
+/// ```
+/// let commit_hash: Option<&str> = compile_time::rustc_commit_hash!();
+/// assert_eq!(commit_hash, rustc_version::version_meta().unwrap().commit_hash.as_deref());
+/// ```
+#[proc_macro]
+pub fn rustc_commit_hash(_item: TokenStream) -> TokenStream {
+  let commit_hash = match &*RUSTC_VERSION_META {
+    Ok(version_meta) => &version_meta.commit_hash,
+    Err(err) => panic!("Failed to get version meta: {}", err),
+  };
+
+  optional_str_tokens(commit_hash).into()
+}
+
+/// Commit date of the Rust compiler as `Option<&'static str>` in `yyyy-MM-dd` format.
+///
+/// `None` if the compiler was not built from a git checkout.
+///
+/// # Example
+///
+/// ```
+/// let commit_date: Option<&str> = compile_time::rustc_commit_date!();
+/// assert_eq!(commit_date, rustc_version::version_meta().unwrap().commit_date.as_deref());
+/// ```
+#[proc_macro]
+pub fn rustc_commit_date(_item: TokenStream) -> TokenStream {
+  let commit_date = match &*RUSTC_VERSION_META {
+    Ok(version_meta) => &version_meta.commit_date,
+    Err(err) => panic!("Failed to get version meta: {}", err),
+  };
+
+  optional_str_tokens(commit_date).into()
+}
+
+/// Commit date of the Rust compiler as `Option<chrono::NaiveDate>`.
+///
+/// `None` if the compiler was not built from a git checkout, or if the commit date
+/// could not be parsed as a date.
+///
+/// # Example
+///
+/// ```
+/// let commit_date: Option<chrono::NaiveDate> = compile_time::rustc_commit_date_naive!();
+/// ```
+#[proc_macro]
+pub fn rustc_commit_date_naive(_item: TokenStream) -> TokenStream {
+  let commit_date = match &*RUSTC_VERSION_META {
+    Ok(version_meta) => &version_meta.commit_date,
+    Err(err) => panic!("Failed to get version meta: {}", err),
+  };
+
+  let date = match commit_date.as_deref().map(|date| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")) {
+    Some(Ok(date)) => date,
+    _ => return quote! { ::core::option::Option::None }.into(),
+  };
+
+  let year = date.year();
+  let month = date.month();
+  let day = date.day();
+
+  quote! {
+    match ::chrono::NaiveDate::from_ymd_opt(#year, #month, #day) {
+      Some(date) => ::core::option::Option::Some(date),
+      _ => ::core::unreachable!(),
+    }
+  }
+  .into()
+}
+
+/// Host target triple of the Rust compiler as `&'static str`.
+///
+/// # Example
+///
+/// ```
+/// const RUSTC_HOST: &str = compile_time::rustc_host!();
+/// assert_eq!(RUSTC_HOST, rustc_version::version_meta().unwrap().host);
+/// ```
+#[proc_macro]
+pub fn rustc_host(_item: TokenStream) -> TokenStream {
+  let host = match &*RUSTC_VERSION_META {
+    Ok(version_meta) => version_meta.host.as_str(),
+    Err(err) => panic!("Failed to get version meta: {}", err),
+  };
+
+  quote! { #host }.into()
+}
+
+/// LLVM version used by the Rust compiler as `Option<&'static str>`.
+///
+/// `None` if the compiler does not report an LLVM version.
+///
+/// # Example
+///
+/// ```
+/// let llvm_version: Option<&str> = compile_time::rustc_llvm_version!();
+/// ```
+#[proc_macro]
+pub fn rustc_llvm_version(_item: TokenStream) -> TokenStream {
+  let llvm_version = match &*RUSTC_VERSION_META {
+    Ok(version_meta) => version_meta.llvm_version.as_ref().map(|llvm_version| llvm_version.to_string()),
+    Err(err) => panic!("Failed to get version meta: {}", err),
+  };
+
+  optional_str_tokens(&llvm_version).into()
+}
+
+fn parse_version_arg(item: TokenStream) -> Result<semver::Version, TokenStream> {
+  let lit = syn::parse::<LitStr>(item).map_err(|err| TokenStream::from(err.to_compile_error()))?;
+
+  semver::Version::parse(&lit.value())
+    .map_err(|err| TokenStream::from(syn::Error::new(lit.span(), format!("invalid version: {err}")).to_compile_error()))
+}
+
+/// Whether the Rust compiler version is at least the given [`semver::Version`](semver::Version)
+/// literal, as a const-evaluable `bool`.
+///
+/// Build metadata is ignored; pre-release ordering is honored.
+///
+/// # Example
+///
+/// ```
+/// const IS_RECENT: bool = compile_time::rustc_at_least!("1.0.0");
+/// assert!(IS_RECENT);
+///
+/// // Build metadata is ignored for the comparison.
+/// assert_eq!(compile_time::rustc_at_least!("1.0.0"), compile_time::rustc_at_least!("1.0.0+build.1"));
+/// ```
+#[proc_macro]
+pub fn rustc_at_least(item: TokenStream) -> TokenStream {
+  let target = match parse_version_arg(item) {
+    Ok(target) => target,
+    Err(err) => return err,
+  };
+
+  let rustc_version = match &*RUSTC_VERSION {
+    Ok(rustc_version) => rustc_version,
+    Err(err) => panic!("Failed to get version: {}", err),
+  };
+
+  let result = !matches!(rustc_version.cmp_precedence(&target), std::cmp::Ordering::Less);
+  quote! { #result }.into()
+}
+
+/// Whether the Rust compiler version is before the given [`semver::Version`](semver::Version)
+/// literal, as a const-evaluable `bool`.
+///
+/// Build metadata is ignored; pre-release ordering is honored.
+///
+/// # Example
+///
+/// ```
+/// const IS_ANCIENT: bool = compile_time::rustc_before!("1.0.0");
+/// assert!(!IS_ANCIENT);
+///
+/// // Build metadata is ignored for the comparison.
+/// assert_eq!(compile_time::rustc_before!("1.0.0"), compile_time::rustc_before!("1.0.0+build.1"));
+/// ```
+#[proc_macro]
+pub fn rustc_before(item: TokenStream) -> TokenStream {
+  let target = match parse_version_arg(item) {
+    Ok(target) => target,
+    Err(err) => return err,
+  };
+
+  let rustc_version = match &*RUSTC_VERSION {
+    Ok(rustc_version) => rustc_version,
+    Err(err) => panic!("Failed to get version: {}", err),
+  };
+
+  let result = matches!(rustc_version.cmp_precedence(&target), std::cmp::Ordering::Less);
+  quote! { #result }.into()
+}
+
+/// Whether the Rust compiler version is exactly the given [`semver::Version`](semver::Version)
+/// literal, as a const-evaluable `bool`.
+///
+/// Build metadata is ignored; pre-release ordering is honored.
+///
+/// # Example
+///
+/// ```
+/// const IS_EXACT: bool = compile_time::rustc_exactly!("1.0.0");
+/// assert!(!IS_EXACT);
+///
+/// // Build metadata is ignored for the comparison.
+/// assert_eq!(compile_time::rustc_exactly!("1.0.0+build.1"), compile_time::rustc_exactly!("1.0.0+build.2"));
+/// ```
+#[proc_macro]
+pub fn rustc_exactly(item: TokenStream) -> TokenStream {
+  let target = match parse_version_arg(item) {
+    Ok(target) => target,
+    Err(err) => return err,
+  };
+
+  let rustc_version = match &*RUSTC_VERSION {
+    Ok(rustc_version) => rustc_version,
+    Err(err) => panic!("Failed to get version: {}", err),
+  };
+
+  let result = matches!(rustc_version.cmp_precedence(&target), std::cmp::Ordering::Equal);
+  quote! { #result }.into()
+}
+
+fn parse_date_arg(item: TokenStream) -> Result<chrono::NaiveDate, TokenStream> {
+  let lit = syn::parse::<LitStr>(item).map_err(|err| TokenStream::from(err.to_compile_error()))?;
+
+  chrono::NaiveDate::parse_from_str(&lit.value(), "%Y-%m-%d")
+    .map_err(|err| TokenStream::from(syn::Error::new(lit.span(), format!("invalid date: {err}")).to_compile_error()))
+}
+
+fn rustc_commit_date_arg() -> Option<chrono::NaiveDate> {
+  let version_meta = match &*RUSTC_VERSION_META {
+    Ok(version_meta) => version_meta,
+    Err(err) => panic!("Failed to get version meta: {}", err),
+  };
+
+  let commit_date = version_meta.commit_date.as_deref()?;
+  chrono::NaiveDate::parse_from_str(commit_date, "%Y-%m-%d").ok()
+}
+
+/// Whether the Rust compiler's commit date is on or after the given date literal
+/// (`yyyy-MM-dd`), as a const-evaluable `bool`.
+///
+/// Expands to `false` if the compiler does not report a commit date (e.g. a custom build).
+///
+/// # Example
+///
+/// ```
+/// const HAS_RECENT_NIGHTLY: bool = compile_time::rustc_since_date!("2000-01-01");
+/// ```
+#[proc_macro]
+pub fn rustc_since_date(item: TokenStream) -> TokenStream {
+  let target = match parse_date_arg(item) {
+    Ok(target) => target,
+    Err(err) => return err,
+  };
+
+  let result = matches!(rustc_commit_date_arg(), Some(commit_date) if commit_date >= target);
+  quote! { #result }.into()
+}
+
+/// Whether the Rust compiler's commit date is before the given date literal
+/// (`yyyy-MM-dd`), as a const-evaluable `bool`.
+///
+/// Expands to `false` if the compiler does not report a commit date (e.g. a custom build).
+///
+/// # Example
+///
+/// ```
+/// const PREDATES_EPOCH: bool = compile_time::rustc_before_date!("1970-01-01");
+/// assert!(!PREDATES_EPOCH);
+/// ```
+#[proc_macro]
+pub fn rustc_before_date(item: TokenStream) -> TokenStream {
+  let target = match parse_date_arg(item) {
+    Ok(target) => target,
+    Err(err) => return err,
+  };
+
+  let result = matches!(rustc_commit_date_arg(), Some(commit_date) if commit_date < target);
+  quote! { #result }.into()
+}
+
+/// Whether the Rust compiler's commit date is exactly the given date literal
+/// (`yyyy-MM-dd`), as a const-evaluable `bool`.
+///
+/// Expands to `false` if the compiler does not report a commit date (e.g. a custom build).
+///
+/// # Example
+///
+/// ```
+/// const BUILT_ON_EPOCH: bool = compile_time::rustc_on_date!("1970-01-01");
+/// assert!(!BUILT_ON_EPOCH);
+/// ```
+#[proc_macro]
+pub fn rustc_on_date(item: TokenStream) -> TokenStream {
+  let target = match parse_date_arg(item) {
+    Ok(target) => target,
+    Err(err) => return err,
+  };
+
+  let result = matches!(rustc_commit_date_arg(), Some(commit_date) if commit_date == target);
+  quote! { #result }.into()
+}
+
+/// Keeps the annotated item only if the Rust compiler version is at least the given
+/// [`semver::Version`](semver::Version) literal, dropping it entirely otherwise.
+///
+/// # Example
+///
+/// ```
+/// #[compile_time::since("1.0.0")]
+/// fn needs_recent_compiler() {}
+///
+/// needs_recent_compiler();
+/// ```
+#[proc_macro_attribute]
+pub fn since(args: TokenStream, item: TokenStream) -> TokenStream {
+  attr::apply(item, attr::parse_bare_version(args, true))
+}
+
+/// Keeps the annotated item only if the Rust compiler version is before the given
+/// [`semver::Version`](semver::Version) literal, dropping it entirely otherwise.
+///
+/// # Example
+///
+/// ```
+/// #[compile_time::before("9999.0.0")]
+/// fn needs_old_compiler() {}
+///
+/// needs_old_compiler();
+/// ```
+#[proc_macro_attribute]
+pub fn before(args: TokenStream, item: TokenStream) -> TokenStream {
+  attr::apply(item, attr::parse_bare_version(args, false))
+}
+
+/// Keeps the annotated item only on the `stable` release channel, dropping it entirely
+/// otherwise.
+///
+/// # Example
+///
+/// ```
+/// #[compile_time::stable]
+/// fn stable_only() {}
+/// ```
+#[proc_macro_attribute]
+pub fn stable(_args: TokenStream, item: TokenStream) -> TokenStream {
+  attr::apply(item, Ok(attr::Predicate::Stable))
+}
+
+/// Keeps the annotated item only on the `nightly` release channel, dropping it entirely
+/// otherwise.
+///
+/// # Example
+///
+/// ```
+/// #[compile_time::nightly]
+/// fn nightly_only() {}
+/// ```
+#[proc_macro_attribute]
+pub fn nightly(_args: TokenStream, item: TokenStream) -> TokenStream {
+  attr::apply(item, Ok(attr::Predicate::Nightly))
+}
+
+/// Keeps the annotated item only on the `beta` release channel, dropping it entirely
+/// otherwise.
+///
+/// # Example
+///
+/// ```
+/// #[compile_time::beta]
+/// fn beta_only() {}
+/// ```
+#[proc_macro_attribute]
+pub fn beta(_args: TokenStream, item: TokenStream) -> TokenStream {
+  attr::apply(item, Ok(attr::Predicate::Beta))
+}
+
+/// Keeps the annotated item only if all of the given predicates hold, dropping it entirely
+/// otherwise. Predicates are `since(...)`, `before(...)`, `stable`, `nightly`, `beta`,
+/// `any(...)`, `all(...)`, and `not(...)`, and may be nested arbitrarily.
+///
+/// # Example
+///
+/// ```
+/// #[compile_time::attr(since("1.0.0"), not(since("9999.0.0")))]
+/// fn recent_enough() {}
+///
+/// recent_enough();
+/// ```
+#[proc_macro_attribute]
+pub fn attr(args: TokenStream, item: TokenStream) -> TokenStream {
+  attr::apply(item, attr::parse_conjunction(args))
+}